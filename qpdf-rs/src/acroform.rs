@@ -0,0 +1,217 @@
+use crate::{QpdfArray, QpdfDictionary, QpdfObject, QpdfObjectLike, Qpdf, Result};
+
+impl Qpdf {
+    /// Return the document's interactive form, if it has one (i.e. the catalog's `/AcroForm`
+    /// entry is present).
+    pub fn acroform(&self) -> Result<Option<QpdfAcroForm>> {
+        let root = match self.get_root()? {
+            Some(root) => root,
+            None => return Ok(None),
+        };
+
+        Ok(root.get("/AcroForm").map(|acroform| QpdfAcroForm { dict: acroform.into() }))
+    }
+}
+
+/// The document's interactive form (`/AcroForm`), giving access to its fields.
+pub struct QpdfAcroForm<'a> {
+    dict: QpdfDictionary<'a>,
+}
+
+impl<'a> QpdfAcroForm<'a> {
+    /// Return all form fields, flattening the field hierarchy. A node that carries `/FT`, or
+    /// has no `/Kids`, is returned as a field; any other node is descended into through its
+    /// `/Kids`. The `/Kids` of a node that carries `/FT` are not returned, even if they carry
+    /// `/T` themselves.
+    pub fn fields(&self) -> Vec<QpdfFormField<'a>> {
+        let mut fields = Vec::new();
+        if let Some(array) = self.dict.get("/Fields") {
+            let array: QpdfArray = array.into();
+            for field in array.iter() {
+                collect_fields(field.into(), &mut fields);
+            }
+        }
+        fields
+    }
+
+    /// Set `/NeedAppearances` on the AcroForm dictionary, telling viewers to regenerate every
+    /// field's appearance themselves rather than trusting the ones stored in the file.
+    pub fn set_need_appearances(&self, value: bool) {
+        self.dict.set("/NeedAppearances", &self.dict.inner.owner.new_bool(value));
+    }
+}
+
+// A field is "terminal" (has its own value) once it carries `/FT`; fields without `/FT` but
+// with `/Kids` are just organizational nodes in the field hierarchy.
+fn collect_fields<'a>(dict: QpdfDictionary<'a>, out: &mut Vec<QpdfFormField<'a>>) {
+    if dict.has("/FT") || !dict.has("/Kids") {
+        out.push(QpdfFormField { dict });
+        return;
+    }
+
+    let kids: QpdfArray = dict.get("/Kids").unwrap().into();
+    for kid in kids.iter() {
+        collect_fields(kid.into(), out);
+    }
+}
+
+/// A single interactive form field.
+pub struct QpdfFormField<'a> {
+    dict: QpdfDictionary<'a>,
+}
+
+impl<'a> QpdfFormField<'a> {
+    /// The field's fully-qualified name, joining `/T` with its ancestors' `/T` by `.`, as
+    /// required by the PDF spec for fields that share a parent.
+    pub fn get_name(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        let mut dict = Some(self.dict.clone());
+        while let Some(d) = dict {
+            if let Some(t) = d.get("/T") {
+                parts.push(t.as_string());
+            }
+            dict = d.get("/Parent").map(Into::into);
+        }
+        if parts.is_empty() {
+            None
+        } else {
+            parts.reverse();
+            Some(parts.join("."))
+        }
+    }
+
+    /// The field type from `/FT` (`/Tx`, `/Btn`, `/Ch`, or `/Sig`), inherited from an
+    /// ancestor field if not set directly on this one.
+    pub fn get_field_type(&self) -> Option<String> {
+        let mut dict = Some(self.dict.clone());
+        while let Some(d) = dict {
+            if let Some(ft) = d.get("/FT") {
+                return Some(ft.as_name());
+            }
+            dict = d.get("/Parent").map(Into::into);
+        }
+        None
+    }
+
+    /// The field's current value (`/V`), inherited from an ancestor field if not set directly
+    /// on this one (`/V` is inheritable, same as `/FT`).
+    pub fn get_value(&self) -> Option<QpdfObject<'a>> {
+        let mut dict = Some(self.dict.clone());
+        while let Some(d) = dict {
+            if let Some(v) = d.get("/V") {
+                return Some(v);
+            }
+            dict = d.get("/Parent").map(Into::into);
+        }
+        None
+    }
+
+    /// Set the field's value. When `need_appearances` is `false` and the field is a text or
+    /// choice field (`/Tx`/`/Ch`), a minimal appearance stream is regenerated for `/AP /N` so
+    /// the new value renders without viewer support. For every other field type — notably
+    /// `/Btn`, whose `/AP /N` is a dictionary of per-state appearances (e.g. `/Yes`/`/Off`)
+    /// picked by `/AS`, not a single drawable stream — regenerating a text appearance would
+    /// destroy it, so `/NeedAppearances` is set instead regardless of `need_appearances`. For
+    /// `/Btn` fields `/AS` is also set to the new value's name so renderers that don't honor
+    /// `/NeedAppearances` still pick the right state out of `/AP /N`.
+    pub fn set_value(&self, value: &QpdfObject, need_appearances: bool) {
+        self.dict.set("/V", value);
+
+        let is_button = self.get_field_type().as_deref() == Some("/Btn");
+        if is_button {
+            self.dict.set("/AS", value);
+        }
+
+        let can_regenerate_text_appearance = matches!(self.get_field_type().as_deref(), Some("/Tx") | Some("/Ch"));
+
+        if need_appearances || !can_regenerate_text_appearance {
+            if let Some(acroform) = self.dict.inner.owner.acroform().ok().flatten() {
+                acroform.set_need_appearances(true);
+            }
+            return;
+        }
+
+        self.regenerate_appearance(value);
+    }
+
+    // Build a minimal text appearance stream from the widget's `/Rect`, good enough to
+    // render the new value without relying on the viewer to regenerate it. Only valid for
+    // `/Tx`/`/Ch` fields; see `set_value`.
+    fn regenerate_appearance(&self, value: &QpdfObject) {
+        let owner = self.dict.inner.owner;
+        let rect: QpdfArray = match self.dict.get("/Rect") {
+            Some(rect) => rect.into(),
+            None => return,
+        };
+
+        // `/Rect` entries may be integers or reals; their unparsed text parses as `f64` either way.
+        let height = rect.get(3).unwrap().to_string().parse::<f64>().unwrap_or(0.0)
+            - rect.get(1).unwrap().to_string().parse::<f64>().unwrap_or(0.0);
+        let width = rect.get(2).unwrap().to_string().parse::<f64>().unwrap_or(0.0)
+            - rect.get(0).unwrap().to_string().parse::<f64>().unwrap_or(0.0);
+
+        let content = format!(
+            "/Tx BMC q BT /Helv {font_size} Tf 2 {baseline} Td ({text}) Tj ET Q EMC\n",
+            font_size = (height * 0.7).max(1.0),
+            baseline = height * 0.2,
+            text = value.as_string().replace('\\', "\\\\").replace('(', "\\(").replace(')', "\\)"),
+        );
+
+        let appearance = owner.new_stream_with_dictionary(
+            [
+                ("/Type", owner.new_name("/XObject")),
+                ("/Subtype", owner.new_name("/Form")),
+                ("/BBox", owner.parse_object(&format!("[0 0 {width} {height}]")).unwrap()),
+                ("/Resources", self.appearance_resources()),
+            ],
+            content.as_bytes(),
+        );
+
+        let ap = match self.dict.get("/AP") {
+            Some(ap) => ap.into(),
+            None => {
+                let ap = owner.new_dictionary();
+                self.dict.set("/AP", ap.inner());
+                ap
+            }
+        };
+        ap.set("/N", &appearance.make_indirect());
+    }
+
+    // `/Resources` for the appearance stream built above: the AcroForm's shared `/DR` if it
+    // already has a usable `/Font /Helv`, otherwise a minimal built-in Helvetica font dict so
+    // the `/Helv Tf` the content stream references can actually be resolved.
+    fn appearance_resources(&self) -> QpdfObject<'a> {
+        let owner = self.dict.inner.owner;
+
+        if let Some(acroform) = owner.acroform().ok().flatten() {
+            if let Some(dr) = acroform.dict.get("/DR") {
+                let dr_dict: QpdfDictionary = dr.clone().into();
+                if let Some(font) = dr_dict.get("/Font") {
+                    let font_dict: QpdfDictionary = font.into();
+                    if font_dict.has("/Helv") {
+                        return dr;
+                    }
+                }
+            }
+        }
+
+        owner.new_dictionary_from([(
+            "/Font",
+            owner
+                .new_dictionary_from([(
+                    "/Helv",
+                    owner
+                        .new_dictionary_from([
+                            ("/Type", owner.new_name("/Font")),
+                            ("/Subtype", owner.new_name("/Type1")),
+                            ("/BaseFont", owner.new_name("/Helvetica")),
+                            ("/Encoding", owner.new_name("/WinAnsiEncoding")),
+                        ])
+                        .inner,
+                )])
+                .inner,
+        )])
+        .inner
+    }
+}
@@ -0,0 +1,160 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{name_tree::QpdfNameTree, stream::QpdfStreamData, Error, QpdfDictionary, QpdfObjectLike, Qpdf, Result, StreamDecodeLevel};
+
+impl Qpdf {
+    /// Return all embedded file attachments, read from the document-level
+    /// `/Root /Names /EmbeddedFiles` name tree.
+    pub fn attachments(&self) -> Result<Vec<QpdfAttachment>> {
+        let root = match self.get_root()? {
+            Some(root) => root,
+            None => return Ok(Vec::new()),
+        };
+
+        let names: QpdfDictionary = match root.get("/Names") {
+            Some(names) => names.into(),
+            None => return Ok(Vec::new()),
+        };
+
+        let embedded_files = match names.get("/EmbeddedFiles") {
+            Some(embedded_files) => embedded_files,
+            None => return Ok(Vec::new()),
+        };
+
+        Ok(QpdfNameTree::new(embedded_files)
+            .iter()
+            .map(|(_, filespec)| QpdfAttachment { filespec: filespec.into() })
+            .collect())
+    }
+
+    /// Embed `data` as a file attachment named `filename`, registering it under `key` in the
+    /// document-level `/Root /Names /EmbeddedFiles` name tree. Builds the `/Filespec`
+    /// dictionary and the embedded-file stream (`/EF /F`, `/Subtype` from `mime_type`, and a
+    /// `/Params` dictionary with the data's size and the current time as `/CreationDate`).
+    pub fn add_attachment(&self, key: &str, filename: &str, data: &[u8], mime_type: &str, description: &str) -> Result<()> {
+        let ef_stream = self.new_stream_with_dictionary(
+            [
+                ("/Type", self.new_name("/EmbeddedFile")),
+                ("/Subtype", self.new_name(&mime_type_to_pdf_subtype(mime_type))),
+                (
+                    "/Params",
+                    self.new_dictionary_from([
+                        ("/Size", self.new_integer(data.len() as i64)),
+                        ("/CreationDate", self.new_string(&pdf_date_now())),
+                    ])
+                    .inner,
+                ),
+            ],
+            data,
+        );
+
+        let filespec = self.new_dictionary_from([
+            ("/Type", self.new_name("/Filespec")),
+            ("/F", self.new_string(filename)),
+            ("/UF", self.new_string(filename)),
+            ("/Desc", self.new_string(description)),
+            ("/EF", self.new_dictionary_from([("/F", ef_stream.make_indirect())]).inner),
+        ]);
+
+        let root = self
+            .get_root()?
+            .ok_or_else(|| Error::InvalidOperation("cannot attach a file to a document with no root".to_owned()))?;
+        let root: QpdfDictionary = root.into();
+
+        let names = match root.get("/Names") {
+            Some(names) => names.into(),
+            None => {
+                let names = self.new_dictionary();
+                root.set("/Names", names.inner());
+                names
+            }
+        };
+
+        let embedded_files = match names.get("/EmbeddedFiles") {
+            Some(embedded_files) => embedded_files,
+            None => {
+                let embedded_files = self.new_dictionary_from([("/Names", self.new_array().inner().clone())]);
+                names.set("/EmbeddedFiles", embedded_files.inner());
+                embedded_files.inner
+            }
+        };
+
+        QpdfNameTree::new(embedded_files).insert(key, &filespec.inner.make_indirect());
+
+        Ok(())
+    }
+}
+
+/// A single embedded file attachment (a `/Filespec` dictionary from the `/EmbeddedFiles`
+/// name tree).
+pub struct QpdfAttachment<'a> {
+    filespec: QpdfDictionary<'a>,
+}
+
+impl<'a> QpdfAttachment<'a> {
+    /// The attachment's filename (`/UF` if present, falling back to `/F`).
+    pub fn get_name(&self) -> Option<String> {
+        self.filespec
+            .get("/UF")
+            .or_else(|| self.filespec.get("/F"))
+            .map(|v| v.as_string())
+    }
+
+    /// The attachment's human-readable description (`/Desc`), if any.
+    pub fn get_description(&self) -> Option<String> {
+        self.filespec.get("/Desc").map(|v| v.as_string())
+    }
+
+    /// The attachment's creation date (`/EF /F /Params /CreationDate`), as a raw PDF date
+    /// string, if set.
+    pub fn get_creation_date(&self) -> Option<String> {
+        self.embedded_file_params()?.get("/CreationDate").map(|v| v.as_string())
+    }
+
+    /// The attachment's raw file data.
+    pub fn get_data(&self) -> Result<QpdfStreamData> {
+        let ef: QpdfDictionary = self.filespec.get("/EF").unwrap().into();
+        let stream = ef.get("/F").unwrap();
+        stream.get_stream_data(StreamDecodeLevel::All)
+    }
+
+    fn embedded_file_params(&self) -> Option<QpdfDictionary<'a>> {
+        let ef: QpdfDictionary = self.filespec.get("/EF")?.into();
+        let stream = ef.get("/F")?;
+        let stream_dict: QpdfDictionary = stream.get_stream_dictionary().into();
+        stream_dict.get("/Params").map(Into::into)
+    }
+}
+
+// Map a MIME type to a `/Subtype` name. The slash is kept in the name's value; qpdf writes
+// it as `#2F` when the name is unparsed, since names can't contain a literal slash.
+fn mime_type_to_pdf_subtype(mime_type: &str) -> String {
+    format!("/{mime_type}")
+}
+
+// A minimal "D:YYYYMMDDHHMMSSZ" PDF date string for the current time, computed from the Unix
+// epoch without pulling in a date/time dependency.
+fn pdf_date_now() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let days = secs / 86_400;
+    let time_of_day = secs % 86_400;
+    let (hour, minute, second) = (time_of_day / 3600, (time_of_day / 60) % 60, time_of_day % 60);
+    let (year, month, day) = civil_from_days(days as i64);
+
+    format!("D:{year:04}{month:02}{day:02}{hour:02}{minute:02}{second:02}Z")
+}
+
+// Howard Hinnant's `civil_from_days`: days since the Unix epoch to a proleptic Gregorian
+// (year, month, day), used so `pdf_date_now` doesn't need a date/time dependency.
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
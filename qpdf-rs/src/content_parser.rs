@@ -0,0 +1,74 @@
+use std::{ops::ControlFlow, os::raw::c_void};
+
+use crate::{QpdfObject, Qpdf, Result};
+
+/// Callbacks invoked while walking the operators and operands of a content stream.
+///
+/// Implement this to inspect a page's (or a standalone stream's) content without writing
+/// a PDF lexer by hand. Pass an implementation to [`Qpdf::parse_content_stream`].
+pub trait ContentParserCallbacks {
+    /// Called once per parsed token/object, in stream order. `offset` and `length` give the
+    /// token's position within the content stream, in bytes. Return `ControlFlow::Break` to
+    /// stop parsing early, mirroring qpdf's `terminateParsing`.
+    fn handle_object(&mut self, obj: QpdfObject, offset: usize, length: usize) -> ControlFlow<()>;
+
+    /// Called once, before the first `handle_object`, with the total size of the content
+    /// stream being parsed. The default implementation ignores it.
+    fn content_size(&mut self, size: usize) {
+        let _ = size;
+    }
+}
+
+// Bundles the owning `Qpdf` (needed to wrap each `qpdf_oh` as a `QpdfObject`) with the user
+// callbacks and the flag used to signal early termination, since the C trampoline only gives
+// us a `*mut c_void` to round-trip through `qpdf_sys`.
+struct CallbackState<'a> {
+    owner: &'a Qpdf,
+    callbacks: &'a mut dyn ContentParserCallbacks,
+    stopped: bool,
+}
+
+impl Qpdf {
+    /// Parse the content stream of `page_or_stream`, invoking `callbacks` for every token.
+    ///
+    /// `page_or_stream` must be a page dictionary or a stream object; anything else results
+    /// in a [`crate::Error`].
+    pub fn parse_content_stream(&self, page_or_stream: &QpdfObject, callbacks: &mut dyn ContentParserCallbacks) -> Result<()> {
+        let mut state = CallbackState {
+            owner: self,
+            callbacks,
+            stopped: false,
+        };
+
+        unsafe {
+            qpdf_sys::qpdf_oh_parse_content_stream(
+                self.inner,
+                page_or_stream.inner,
+                Some(content_size_trampoline),
+                Some(handle_object_trampoline),
+                &mut state as *mut CallbackState as *mut c_void,
+            );
+        }
+
+        self.last_error_or_then(|| ())
+    }
+}
+
+unsafe extern "C" fn content_size_trampoline(data: *mut c_void, size: usize) {
+    let state = &mut *(data as *mut CallbackState);
+    state.callbacks.content_size(size);
+}
+
+unsafe extern "C" fn handle_object_trampoline(data: *mut c_void, oh: qpdf_sys::qpdf_oh, offset: usize, length: usize) -> i32 {
+    let state = &mut *(data as *mut CallbackState);
+    if state.stopped {
+        return 1;
+    }
+
+    let obj = QpdfObject::new(state.owner, oh);
+    if state.callbacks.handle_object(obj, offset, length).is_break() {
+        state.stopped = true;
+    }
+
+    state.stopped as i32
+}
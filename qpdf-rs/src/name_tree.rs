@@ -0,0 +1,377 @@
+use crate::{QpdfArray, QpdfDictionary, QpdfObject, QpdfObjectLike};
+
+// PDF name/number trees are balanced, possibly multi-level structures: an intermediate node
+// has `/Kids` (each kid annotated with a `/Limits` range) while a leaf has `/Names` (or
+// `/Nums`) as a flat `[key1, value1, key2, value2, ...]` array. Real-world trees (`/Dests`,
+// `/EmbeddedFiles`, page labels, struct parent maps) are rarely large enough to need the
+// rebalancing qpdf's own `QPDFNameTreeObjectHelper` performs on insert, so these helpers walk
+// `/Limits` to find the right leaf but otherwise keep a single leaf's array sorted in place.
+// Inserting outside a leaf's current range still has to widen `/Limits` back up to the root,
+// since `find`/`leaf_for` gate descent on it.
+
+/// A thin wrapper around the dictionary at the root of a PDF name tree (`/Dests`,
+/// `/EmbeddedFiles`, and similar `/Names`-keyed structures).
+pub struct QpdfNameTree<'a> {
+    root: QpdfDictionary<'a>,
+}
+
+impl<'a> QpdfNameTree<'a> {
+    /// Wrap `root`, the dictionary at the root of the tree.
+    pub fn new(root: QpdfObject<'a>) -> Self {
+        QpdfNameTree { root: root.into() }
+    }
+
+    /// Look up `key`, returning its value if present.
+    pub fn find(&self, key: &str) -> Option<QpdfObject<'a>> {
+        fn go<'a>(node: &QpdfDictionary<'a>, key: &str) -> Option<QpdfObject<'a>> {
+            if node.has("/Kids") {
+                let kids: QpdfArray = node.get("/Kids").unwrap().into();
+                for kid in kids.iter() {
+                    let kid: QpdfDictionary = kid.into();
+                    let limits: QpdfArray = kid.get("/Limits").unwrap().into();
+                    let lo = limits.get(0).unwrap().as_string();
+                    let hi = limits.get(1).unwrap().as_string();
+                    if lo.as_str() <= key && key <= hi.as_str() {
+                        return go(&kid, key);
+                    }
+                }
+                None
+            } else {
+                let array: QpdfArray = node.get("/Names")?.into();
+                let mut i = 0;
+                while i < array.len() {
+                    if array.get(i).unwrap().as_string() == key {
+                        return array.get(i + 1);
+                    }
+                    i += 2;
+                }
+                None
+            }
+        }
+        go(&self.root, key)
+    }
+
+    /// Insert or replace the value stored under `key`.
+    pub fn insert(&self, key: &str, value: &QpdfObject) {
+        let path = self.path_to_leaf(key);
+        let leaf = path.last().cloned().unwrap_or_else(|| self.root.clone());
+        let owner = leaf.inner.owner;
+        let array: QpdfArray = match leaf.get("/Names") {
+            Some(array) => array.into(),
+            None => {
+                let array = owner.new_array();
+                leaf.set("/Names", array.inner());
+                array
+            }
+        };
+
+        let mut i = 0;
+        let mut replaced = false;
+        while i < array.len() {
+            let existing = array.get(i).unwrap().as_string();
+            if existing == key {
+                array.set(i + 1, value);
+                replaced = true;
+                break;
+            }
+            if existing.as_str() > key {
+                break;
+            }
+            i += 2;
+        }
+        if !replaced {
+            array.insert(i, &owner.new_string(key));
+            array.insert(i + 1, value);
+        }
+
+        update_limits_string(&path, &array);
+    }
+
+    /// Remove the entry stored under `key`, if any.
+    pub fn remove(&self, key: &str) {
+        let leaf = self.leaf_for(key);
+        let array: QpdfArray = match leaf.get("/Names") {
+            Some(array) => array.into(),
+            None => return,
+        };
+
+        let mut i = 0;
+        while i < array.len() {
+            if array.get(i).unwrap().as_string() == key {
+                array.remove(i + 1);
+                array.remove(i);
+                return;
+            }
+            i += 2;
+        }
+    }
+
+    /// Iterate over all entries in the tree, in sorted key order.
+    pub fn iter(&self) -> impl Iterator<Item = (String, QpdfObject<'a>)> {
+        fn collect<'a>(node: &QpdfDictionary<'a>, out: &mut Vec<(String, QpdfObject<'a>)>) {
+            if node.has("/Kids") {
+                let kids: QpdfArray = node.get("/Kids").unwrap().into();
+                for kid in kids.iter() {
+                    collect(&kid.into(), out);
+                }
+            } else if let Some(array) = node.get("/Names") {
+                let array: QpdfArray = array.into();
+                let mut i = 0;
+                while i < array.len() {
+                    out.push((array.get(i).unwrap().as_string(), array.get(i + 1).unwrap()));
+                    i += 2;
+                }
+            }
+        }
+        let mut entries = Vec::new();
+        collect(&self.root, &mut entries);
+        entries.into_iter()
+    }
+
+    // Descend through `/Kids`, following whichever child's `/Limits` could contain `key`,
+    // down to the leaf dictionary that owns (or should own) it.
+    fn leaf_for(&self, key: &str) -> QpdfDictionary<'a> {
+        self.path_to_leaf(key).pop().unwrap_or_else(|| self.root.clone())
+    }
+
+    // Same descent as `leaf_for`, but keeps every visited kid along the way (each one has
+    // its own `/Limits` entry in its parent's `/Kids`), so `insert` can widen `/Limits` back
+    // up to the root when the new key falls outside the leaf's current range. Empty when the
+    // root itself has no `/Kids` (a single flat leaf).
+    fn path_to_leaf(&self, key: &str) -> Vec<QpdfDictionary<'a>> {
+        let mut path = Vec::new();
+        let mut node = self.root.clone();
+        while node.has("/Kids") {
+            let kids: QpdfArray = node.get("/Kids").unwrap().into();
+            let mut next = None;
+            for kid in kids.iter() {
+                let kid: QpdfDictionary = kid.into();
+                let limits: QpdfArray = kid.get("/Limits").unwrap().into();
+                let hi = limits.get(1).unwrap().as_string();
+                if key <= hi.as_str() {
+                    next = Some(kid);
+                    break;
+                }
+            }
+            let chosen = next.unwrap_or_else(|| {
+                let kids: QpdfArray = node.get("/Kids").unwrap().into();
+                kids.iter().last().unwrap().into()
+            });
+            path.push(chosen.clone());
+            node = chosen;
+        }
+        path
+    }
+}
+
+// After `leaf`'s (the last entry of `path`) `/Names` array has been updated, recompute its
+// `/Limits` from the array's now-first and now-last keys, then walk back up `path` widening
+// each ancestor's `/Limits` to cover its children, so a key inserted outside the previous
+// bounds stays reachable through `find`.
+fn update_limits_string(path: &[QpdfDictionary], array: &QpdfArray) {
+    let Some(leaf) = path.last() else { return };
+
+    let lo = array.get(0).unwrap().as_string();
+    let hi = array.get(array.len() - 2).unwrap().as_string();
+    set_limits_string(leaf, &lo, &hi);
+
+    for idx in (0..path.len() - 1).rev() {
+        let (node, child) = (&path[idx], &path[idx + 1]);
+        let child_limits: QpdfArray = child.get("/Limits").unwrap().into();
+        let node_limits: QpdfArray = node.get("/Limits").unwrap().into();
+
+        let lo = child_limits.get(0).unwrap().as_string().min(node_limits.get(0).unwrap().as_string());
+        let hi = child_limits.get(1).unwrap().as_string().max(node_limits.get(1).unwrap().as_string());
+        set_limits_string(node, &lo, &hi);
+    }
+}
+
+fn set_limits_string(node: &QpdfDictionary, lo: &str, hi: &str) {
+    let owner = node.inner.owner;
+    let mut limits = owner.new_array();
+    limits.push(&owner.new_string(lo));
+    limits.push(&owner.new_string(hi));
+    node.set("/Limits", limits.inner());
+}
+
+/// A thin wrapper around the dictionary at the root of a PDF number tree (page labels,
+/// structure parent maps, and similar `/Nums`-keyed structures).
+pub struct QpdfNumberTree<'a> {
+    root: QpdfDictionary<'a>,
+}
+
+impl<'a> QpdfNumberTree<'a> {
+    /// Wrap `root`, the dictionary at the root of the tree.
+    pub fn new(root: QpdfObject<'a>) -> Self {
+        QpdfNumberTree { root: root.into() }
+    }
+
+    /// Look up `key`, returning its value if present.
+    pub fn find(&self, key: i64) -> Option<QpdfObject<'a>> {
+        fn go<'a>(node: &QpdfDictionary<'a>, key: i64) -> Option<QpdfObject<'a>> {
+            if node.has("/Kids") {
+                let kids: QpdfArray = node.get("/Kids").unwrap().into();
+                for kid in kids.iter() {
+                    let kid: QpdfDictionary = kid.into();
+                    let limits: QpdfArray = kid.get("/Limits").unwrap().into();
+                    let lo = limits.get(0).unwrap().as_i64();
+                    let hi = limits.get(1).unwrap().as_i64();
+                    if lo <= key && key <= hi {
+                        return go(&kid, key);
+                    }
+                }
+                None
+            } else {
+                let array: QpdfArray = node.get("/Nums")?.into();
+                let mut i = 0;
+                while i < array.len() {
+                    if array.get(i).unwrap().as_i64() == key {
+                        return array.get(i + 1);
+                    }
+                    i += 2;
+                }
+                None
+            }
+        }
+        go(&self.root, key)
+    }
+
+    /// Insert or replace the value stored under `key`.
+    pub fn insert(&self, key: i64, value: &QpdfObject) {
+        let path = self.path_to_leaf(key);
+        let leaf = path.last().cloned().unwrap_or_else(|| self.root.clone());
+        let owner = leaf.inner.owner;
+        let array: QpdfArray = match leaf.get("/Nums") {
+            Some(array) => array.into(),
+            None => {
+                let array = owner.new_array();
+                leaf.set("/Nums", array.inner());
+                array
+            }
+        };
+
+        let mut i = 0;
+        let mut replaced = false;
+        while i < array.len() {
+            let existing = array.get(i).unwrap().as_i64();
+            if existing == key {
+                array.set(i + 1, value);
+                replaced = true;
+                break;
+            }
+            if existing > key {
+                break;
+            }
+            i += 2;
+        }
+        if !replaced {
+            array.insert(i, &owner.new_integer(key));
+            array.insert(i + 1, value);
+        }
+
+        update_limits_i64(&path, &array);
+    }
+
+    /// Remove the entry stored under `key`, if any.
+    pub fn remove(&self, key: i64) {
+        let leaf = self.leaf_for(key);
+        let array: QpdfArray = match leaf.get("/Nums") {
+            Some(array) => array.into(),
+            None => return,
+        };
+
+        let mut i = 0;
+        while i < array.len() {
+            if array.get(i).unwrap().as_i64() == key {
+                array.remove(i + 1);
+                array.remove(i);
+                return;
+            }
+            i += 2;
+        }
+    }
+
+    /// Iterate over all entries in the tree, in sorted key order.
+    pub fn iter(&self) -> impl Iterator<Item = (i64, QpdfObject<'a>)> {
+        fn collect<'a>(node: &QpdfDictionary<'a>, out: &mut Vec<(i64, QpdfObject<'a>)>) {
+            if node.has("/Kids") {
+                let kids: QpdfArray = node.get("/Kids").unwrap().into();
+                for kid in kids.iter() {
+                    collect(&kid.into(), out);
+                }
+            } else if let Some(array) = node.get("/Nums") {
+                let array: QpdfArray = array.into();
+                let mut i = 0;
+                while i < array.len() {
+                    out.push((array.get(i).unwrap().as_i64(), array.get(i + 1).unwrap()));
+                    i += 2;
+                }
+            }
+        }
+        let mut entries = Vec::new();
+        collect(&self.root, &mut entries);
+        entries.into_iter()
+    }
+
+    // Descend through `/Kids`, following whichever child's `/Limits` could contain `key`,
+    // down to the leaf dictionary that owns (or should own) it.
+    fn leaf_for(&self, key: i64) -> QpdfDictionary<'a> {
+        self.path_to_leaf(key).pop().unwrap_or_else(|| self.root.clone())
+    }
+
+    // Same descent as `leaf_for`, but keeps every visited kid along the way (each one has
+    // its own `/Limits` entry in its parent's `/Kids`), so `insert` can widen `/Limits` back
+    // up to the root when the new key falls outside the leaf's current range. Empty when the
+    // root itself has no `/Kids` (a single flat leaf).
+    fn path_to_leaf(&self, key: i64) -> Vec<QpdfDictionary<'a>> {
+        let mut path = Vec::new();
+        let mut node = self.root.clone();
+        while node.has("/Kids") {
+            let kids: QpdfArray = node.get("/Kids").unwrap().into();
+            let mut next = None;
+            for kid in kids.iter() {
+                let kid: QpdfDictionary = kid.into();
+                let limits: QpdfArray = kid.get("/Limits").unwrap().into();
+                let hi = limits.get(1).unwrap().as_i64();
+                if key <= hi {
+                    next = Some(kid);
+                    break;
+                }
+            }
+            let chosen = next.unwrap_or_else(|| {
+                let kids: QpdfArray = node.get("/Kids").unwrap().into();
+                kids.iter().last().unwrap().into()
+            });
+            path.push(chosen.clone());
+            node = chosen;
+        }
+        path
+    }
+}
+
+// Mirrors `update_limits_string` for number-tree keys.
+fn update_limits_i64(path: &[QpdfDictionary], array: &QpdfArray) {
+    let Some(leaf) = path.last() else { return };
+
+    let lo = array.get(0).unwrap().as_i64();
+    let hi = array.get(array.len() - 2).unwrap().as_i64();
+    set_limits_i64(leaf, lo, hi);
+
+    for idx in (0..path.len() - 1).rev() {
+        let (node, child) = (&path[idx], &path[idx + 1]);
+        let child_limits: QpdfArray = child.get("/Limits").unwrap().into();
+        let node_limits: QpdfArray = node.get("/Limits").unwrap().into();
+
+        let lo = child_limits.get(0).unwrap().as_i64().min(node_limits.get(0).unwrap().as_i64());
+        let hi = child_limits.get(1).unwrap().as_i64().max(node_limits.get(1).unwrap().as_i64());
+        set_limits_i64(node, lo, hi);
+    }
+}
+
+fn set_limits_i64(node: &QpdfDictionary, lo: i64, hi: i64) {
+    let owner = node.inner.owner;
+    let mut limits = owner.new_array();
+    limits.push(&owner.new_integer(lo));
+    limits.push(&owner.new_integer(hi));
+    node.set("/Limits", limits.inner());
+}
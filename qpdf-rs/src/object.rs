@@ -1,4 +1,8 @@
-use std::{cmp::Ordering, ffi::CStr, fmt, ptr, slice};
+use std::{
+    cmp::Ordering,
+    ffi::{CStr, CString},
+    fmt, ptr, slice,
+};
 
 use crate::{stream::QpdfStreamData, Qpdf, Result};
 
@@ -125,6 +129,23 @@ pub trait QpdfObjectLike {
         self.inner().is_stream()
     }
 
+    /// Return true if this is a name object whose value equals `name`
+    fn is_name_and_equals(&self, name: &str) -> bool {
+        self.inner().is_name_and_equals(name)
+    }
+
+    /// Return true if this is a dictionary whose `/Type` matches `type_` and, if `subtype`
+    /// is given, whose `/Subtype` matches it too
+    fn is_dictionary_of_type(&self, type_: &str, subtype: Option<&str>) -> bool {
+        self.inner().is_dictionary_of_type(type_, subtype)
+    }
+
+    /// Return true if this is a stream whose dictionary's `/Type` matches `type_` and, if
+    /// `subtype` is given, whose `/Subtype` matches it too
+    fn is_stream_of_type(&self, type_: &str, subtype: Option<&str>) -> bool {
+        self.inner().is_stream_of_type(type_, subtype)
+    }
+
     /// Get boolean value
     fn as_bool(&self) -> bool {
         self.inner().as_bool()
@@ -171,6 +192,21 @@ impl<'a> QpdfObject<'a> {
     pub(crate) fn new(owner: &'a Qpdf, inner: qpdf_sys::qpdf_oh) -> Self {
         QpdfObject { owner, inner }
     }
+
+    // Return true if `self` is a dictionary with a name value `name` stored under `key`
+    fn dict_key_is_name(&self, key: &str, name: &str) -> bool {
+        let key = CString::new(key).unwrap();
+        unsafe {
+            if qpdf_sys::qpdf_oh_has_key(self.owner.inner, self.inner, key.as_ptr()) == 0 {
+                return false;
+            }
+            let value = QpdfObject::new(
+                self.owner,
+                qpdf_sys::qpdf_oh_get_key(self.owner.inner, self.inner, key.as_ptr()),
+            );
+            value.is_name_and_equals(name)
+        }
+    }
 }
 
 impl<'a> QpdfObjectLike for QpdfObject<'a> {
@@ -251,6 +287,27 @@ impl<'a> QpdfObjectLike for QpdfObject<'a> {
         unsafe { qpdf_sys::qpdf_oh_is_stream(self.owner.inner, self.inner) != 0 }
     }
 
+    fn is_name_and_equals(&self, name: &str) -> bool {
+        self.is_name() && self.as_name() == name
+    }
+
+    fn is_dictionary_of_type(&self, type_: &str, subtype: Option<&str>) -> bool {
+        self.is_dictionary()
+            && self.dict_key_is_name("/Type", type_)
+            && subtype.map_or(true, |st| self.dict_key_is_name("/Subtype", st))
+    }
+
+    fn is_stream_of_type(&self, type_: &str, subtype: Option<&str>) -> bool {
+        if !self.is_stream() {
+            return false;
+        }
+
+        unsafe {
+            let dict = QpdfObject::new(self.owner, qpdf_sys::qpdf_oh_get_dict(self.owner.inner, self.inner));
+            dict.is_dictionary_of_type(type_, subtype)
+        }
+    }
+
     fn as_bool(&self) -> bool {
         unsafe { qpdf_sys::qpdf_oh_get_bool_value(self.owner.inner, self.inner) != 0 }
     }
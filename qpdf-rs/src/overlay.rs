@@ -0,0 +1,130 @@
+use crate::{QpdfArray, QpdfDictionary, QpdfObject, QpdfObjectLike, Qpdf, Result};
+
+impl Qpdf {
+    /// Stamp `source_page`'s rendered content onto `dest_page`.
+    ///
+    /// `source_page` is wrapped as a form XObject, given a unique name in `dest_page`'s
+    /// `/Resources /XObject` dictionary, and invoked from a content-stream fragment that maps
+    /// its media box onto `dest_page`'s media box. When `under` is `false` the fragment is
+    /// appended after `dest_page`'s existing content, so the source page is drawn on top
+    /// (overlay); when `under` is `true` it is prepended, so the source page is drawn
+    /// underneath (underlay, e.g. for letterheads or watermarks).
+    pub fn overlay_page(&self, dest_page: &QpdfObject, source_page: &QpdfObject, under: bool) -> Result<()> {
+        let dest_dict: QpdfDictionary = dest_page.clone().into();
+        let source_dict: QpdfDictionary = source_page.clone().into();
+
+        let form = self.page_to_form_xobject(&source_dict)?;
+        let name = self.unique_xobject_name(&dest_dict);
+        self.register_xobject(&dest_dict, &name, &form);
+
+        let ctm = fit_box(&media_box(&source_dict), &media_box(&dest_dict));
+        let fragment = format!(
+            "q {} {} {} {} {} {} cm {} Do Q\n",
+            ctm.0, ctm.1, ctm.2, ctm.3, ctm.4, ctm.5, name
+        );
+
+        let existing = dest_page.get_page_content_data()?;
+        let combined = if under {
+            [fragment.as_bytes(), existing.as_ref()].concat()
+        } else {
+            [existing.as_ref(), fragment.as_bytes()].concat()
+        };
+
+        dest_dict.set("/Contents", &self.new_stream(&combined));
+
+        Ok(())
+    }
+
+    // Wrap a page as a reusable form XObject: same resources and media box, content taken
+    // verbatim from the page.
+    fn page_to_form_xobject(&self, page: &QpdfDictionary) -> Result<QpdfObject> {
+        let content = page.inner.get_page_content_data()?;
+        let resources = page.get("/Resources").unwrap_or_else(|| self.new_dictionary());
+
+        let xobject = self.new_stream_with_dictionary(
+            [
+                ("/Type", self.new_name("/XObject")),
+                ("/Subtype", self.new_name("/Form")),
+                ("/FormType", self.new_integer(1)),
+                ("/BBox", page.get("/MediaBox").unwrap_or_else(|| self.parse_object("[0 0 612 792]").unwrap())),
+                ("/Resources", resources),
+            ],
+            content.as_ref(),
+        );
+
+        Ok(xobject.make_indirect())
+    }
+
+    // Find a `/FmN` name not already used in `dest`'s `/Resources /XObject` dictionary.
+    fn unique_xobject_name(&self, dest: &QpdfDictionary) -> String {
+        let xobjects = self.dest_xobject_dict(dest);
+        let mut n = 1;
+        loop {
+            let name = format!("/Fm{n}");
+            if !xobjects.has(&name) {
+                return name;
+            }
+            n += 1;
+        }
+    }
+
+    fn register_xobject(&self, dest: &QpdfDictionary, name: &str, form: &QpdfObject) {
+        self.dest_xobject_dict(dest).set(name, form);
+    }
+
+    // Return `dest`'s `/Resources /XObject` dictionary, creating either or both if absent.
+    fn dest_xobject_dict(&self, dest: &QpdfDictionary) -> QpdfDictionary {
+        let resources: QpdfDictionary = match dest.get("/Resources") {
+            Some(resources) => resources.into(),
+            None => {
+                let resources = self.new_dictionary();
+                dest.set("/Resources", resources.inner());
+                resources
+            }
+        };
+
+        match resources.get("/XObject") {
+            Some(xobject) => xobject.into(),
+            None => {
+                let xobject = self.new_dictionary();
+                resources.set("/XObject", xobject.inner());
+                xobject
+            }
+        }
+    }
+}
+
+// The page's media box as `(llx, lly, urx, ury)`, defaulting to US Letter if absent. Entries
+// may be integers or reals; their unparsed text parses as `f64` either way.
+fn media_box(page: &QpdfDictionary) -> (f64, f64, f64, f64) {
+    match page.get("/MediaBox") {
+        Some(box_) => {
+            let array: QpdfArray = box_.into();
+            (
+                array.get(0).unwrap().to_string().parse().unwrap_or(0.0),
+                array.get(1).unwrap().to_string().parse().unwrap_or(0.0),
+                array.get(2).unwrap().to_string().parse().unwrap_or(612.0),
+                array.get(3).unwrap().to_string().parse().unwrap_or(792.0),
+            )
+        }
+        None => (0.0, 0.0, 612.0, 792.0),
+    }
+}
+
+// A CTM `(a, b, c, d, e, f)` that scales `src` to fit within `dest`, preserving aspect ratio
+// and centering the result, as in qpdf's `pdf-overlay-page` example.
+fn fit_box(src: &(f64, f64, f64, f64), dest: &(f64, f64, f64, f64)) -> (f64, f64, f64, f64, f64, f64) {
+    let (src_w, src_h) = (src.2 - src.0, src.3 - src.1);
+    let (dest_w, dest_h) = (dest.2 - dest.0, dest.3 - dest.1);
+
+    let scale = if src_w > 0.0 && src_h > 0.0 {
+        (dest_w / src_w).min(dest_h / src_h)
+    } else {
+        1.0
+    };
+
+    let tx = dest.0 - src.0 * scale + (dest_w - src_w * scale) / 2.0;
+    let ty = dest.1 - src.1 * scale + (dest_h - src_h * scale) / 2.0;
+
+    (scale, 0.0, 0.0, scale, tx, ty)
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, ops::ControlFlow};
 
 use qpdf::*;
 
@@ -221,6 +221,30 @@ fn test_dictionary() {
     assert!(dict.get("/MyKey").is_none());
 }
 
+#[test]
+fn test_type_predicates() {
+    let qpdf = Qpdf::empty();
+
+    let name = qpdf.new_name("/Page");
+    assert!(name.is_name_and_equals("/Page"));
+    assert!(!name.is_name_and_equals("/Font"));
+
+    let page = qpdf.new_dictionary_from([("/Type", qpdf.new_name("/Page"))]);
+    assert!(page.inner.is_dictionary_of_type("/Page", None));
+    assert!(!page.inner.is_dictionary_of_type("/Font", None));
+
+    let font = qpdf.new_dictionary_from([
+        ("/Type", qpdf.new_name("/Font")),
+        ("/Subtype", qpdf.new_name("/Type1")),
+    ]);
+    assert!(font.inner.is_dictionary_of_type("/Font", Some("/Type1")));
+    assert!(!font.inner.is_dictionary_of_type("/Font", Some("/TrueType")));
+
+    let stream = qpdf.new_stream_with_dictionary([("/Type", qpdf.new_name("/XObject"))], &[]);
+    assert!(stream.is_stream_of_type("/XObject", None));
+    assert!(!stream.is_stream_of_type("/Font", None));
+}
+
 #[test]
 fn test_strings() {
     let qpdf = Qpdf::empty();
@@ -274,6 +298,372 @@ fn test_pdf_ops() {
     assert_eq!(saved_pdf.get_num_pages().unwrap(), 0);
 }
 
+#[test]
+fn test_content_parser() {
+    struct Collector {
+        sizes: Vec<usize>,
+        operators: Vec<String>,
+    }
+
+    impl ContentParserCallbacks for Collector {
+        fn handle_object(&mut self, obj: QpdfObject, _offset: usize, _length: usize) -> ControlFlow<()> {
+            if obj.is_operator() {
+                self.operators.push(obj.to_string());
+            }
+            ControlFlow::Continue(())
+        }
+
+        fn content_size(&mut self, size: usize) {
+            self.sizes.push(size);
+        }
+    }
+
+    let qpdf = load_pdf();
+    let page = qpdf.get_pages().unwrap().into_iter().next().unwrap();
+
+    let mut collector = Collector {
+        sizes: Vec::new(),
+        operators: Vec::new(),
+    };
+    qpdf.parse_content_stream(&page, &mut collector).unwrap();
+
+    assert_eq!(collector.sizes.len(), 1);
+    assert!(!collector.operators.is_empty());
+}
+
+#[test]
+fn test_content_parser_early_termination() {
+    struct StopAfterFirst(usize);
+
+    impl ContentParserCallbacks for StopAfterFirst {
+        fn handle_object(&mut self, _obj: QpdfObject, _offset: usize, _length: usize) -> ControlFlow<()> {
+            self.0 += 1;
+            ControlFlow::Break(())
+        }
+    }
+
+    let qpdf = load_pdf();
+    let page = qpdf.get_pages().unwrap().into_iter().next().unwrap();
+
+    let mut stop_after_first = StopAfterFirst(0);
+    qpdf.parse_content_stream(&page, &mut stop_after_first).unwrap();
+
+    assert_eq!(stop_after_first.0, 1);
+}
+
+#[test]
+fn test_token_filter() {
+    struct DropComments;
+
+    impl TokenFilter for DropComments {
+        fn handle_token(&mut self, token: Token) -> Vec<Token> {
+            if token.token_type == TokenType::Comment {
+                vec![]
+            } else {
+                vec![token]
+            }
+        }
+    }
+
+    let qpdf = load_pdf();
+    let page = qpdf.get_pages().unwrap().into_iter().next().unwrap();
+    page.add_token_filter(Box::new(DropComments));
+
+    let mem = qpdf.writer().write_to_memory().unwrap();
+    assert!(!mem.is_empty());
+}
+
+#[test]
+fn test_token_string_escapes_parens_and_backslashes() {
+    let token = Token::string("a (nested) \\ value");
+    assert_eq!(token.raw_value, b"(a \\(nested\\) \\\\ value)");
+}
+
+#[test]
+fn test_name_tree() {
+    let qpdf = Qpdf::empty();
+
+    let mut names = qpdf.new_array();
+    names.push(&qpdf.new_string("apple"));
+    names.push(&qpdf.new_integer(1));
+    names.push(&qpdf.new_string("cherry"));
+    names.push(&qpdf.new_integer(3));
+
+    let root = qpdf.new_dictionary_from([("/Names", names.inner().clone())]);
+    let tree = QpdfNameTree::new(root.inner);
+
+    assert_eq!(tree.find("apple").unwrap().as_i32(), 1);
+    assert!(tree.find("banana").is_none());
+
+    tree.insert("banana", &qpdf.new_integer(2));
+    assert_eq!(tree.find("banana").unwrap().as_i32(), 2);
+    assert_eq!(
+        tree.iter().map(|(k, _)| k).collect::<Vec<_>>(),
+        vec!["apple".to_owned(), "banana".to_owned(), "cherry".to_owned()]
+    );
+
+    tree.remove("apple");
+    assert!(tree.find("apple").is_none());
+}
+
+#[test]
+fn test_name_tree_multi_level_insert_widens_limits() {
+    let qpdf = Qpdf::empty();
+
+    let mut kid1_names = qpdf.new_array();
+    kid1_names.push(&qpdf.new_string("B"));
+    kid1_names.push(&qpdf.new_integer(2));
+    kid1_names.push(&qpdf.new_string("D"));
+    kid1_names.push(&qpdf.new_integer(4));
+    let mut kid1_limits = qpdf.new_array();
+    kid1_limits.push(&qpdf.new_string("B"));
+    kid1_limits.push(&qpdf.new_string("D"));
+    let kid1 = qpdf.new_dictionary_from([
+        ("/Limits", kid1_limits.inner().clone()),
+        ("/Names", kid1_names.inner().clone()),
+    ]);
+
+    let mut kid2_names = qpdf.new_array();
+    kid2_names.push(&qpdf.new_string("F"));
+    kid2_names.push(&qpdf.new_integer(6));
+    kid2_names.push(&qpdf.new_string("H"));
+    kid2_names.push(&qpdf.new_integer(8));
+    let mut kid2_limits = qpdf.new_array();
+    kid2_limits.push(&qpdf.new_string("F"));
+    kid2_limits.push(&qpdf.new_string("H"));
+    let kid2 = qpdf.new_dictionary_from([
+        ("/Limits", kid2_limits.inner().clone()),
+        ("/Names", kid2_names.inner().clone()),
+    ]);
+
+    let mut kids = qpdf.new_array();
+    kids.push(kid1.inner());
+    kids.push(kid2.inner());
+    let root = qpdf.new_dictionary_from([("/Kids", kids.inner().clone())]);
+
+    let tree = QpdfNameTree::new(root.inner);
+
+    // "A" sorts before kid1's current range ["B", "D"], so it lands in the leftmost leaf but
+    // falls outside its stale `/Limits` unless insert widens it.
+    tree.insert("A", &qpdf.new_integer(1));
+    assert_eq!(tree.find("A").unwrap().as_i32(), 1);
+
+    let kid1_limits: QpdfArray = kid1.get("/Limits").unwrap().into();
+    assert_eq!(kid1_limits.get(0).unwrap().as_string(), "A");
+    assert_eq!(kid1_limits.get(1).unwrap().as_string(), "D");
+
+    // "Z" sorts after every leaf's range, so it falls in the rightmost leaf (kid2) and must
+    // widen kid2's `/Limits` past "H".
+    tree.insert("Z", &qpdf.new_integer(26));
+    assert_eq!(tree.find("Z").unwrap().as_i32(), 26);
+
+    let kid2_limits: QpdfArray = kid2.get("/Limits").unwrap().into();
+    assert_eq!(kid2_limits.get(0).unwrap().as_string(), "F");
+    assert_eq!(kid2_limits.get(1).unwrap().as_string(), "Z");
+
+    assert_eq!(
+        tree.iter().map(|(k, _)| k).collect::<Vec<_>>(),
+        vec!["A", "B", "D", "F", "H", "Z"].into_iter().map(String::from).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_name_tree_insert_into_empty_root() {
+    let qpdf = Qpdf::empty();
+
+    // A freshly-created root has neither `/Names` nor `/Kids` yet; `insert` must create the
+    // `/Names` array rather than panicking on a missing one.
+    let root = qpdf.new_dictionary();
+    let tree = QpdfNameTree::new(root.inner);
+
+    tree.insert("foo", &qpdf.new_integer(1));
+    assert_eq!(tree.find("foo").unwrap().as_i32(), 1);
+    assert_eq!(tree.iter().map(|(k, _)| k).collect::<Vec<_>>(), vec!["foo".to_owned()]);
+}
+
+#[test]
+fn test_number_tree() {
+    let qpdf = Qpdf::empty();
+
+    let mut nums = qpdf.new_array();
+    nums.push(&qpdf.new_integer(0));
+    nums.push(&qpdf.new_string("zero"));
+    nums.push(&qpdf.new_integer(10));
+    nums.push(&qpdf.new_string("ten"));
+
+    let root = qpdf.new_dictionary_from([("/Nums", nums.inner().clone())]);
+    let tree = QpdfNumberTree::new(root.inner);
+
+    assert_eq!(tree.find(0).unwrap().as_string(), "zero");
+    assert!(tree.find(5).is_none());
+
+    tree.insert(5, &qpdf.new_string("five"));
+    assert_eq!(tree.find(5).unwrap().as_string(), "five");
+    assert_eq!(tree.iter().map(|(k, _)| k).collect::<Vec<_>>(), vec![0, 5, 10]);
+
+    tree.remove(10);
+    assert!(tree.find(10).is_none());
+}
+
+#[test]
+fn test_number_tree_insert_into_empty_root() {
+    let qpdf = Qpdf::empty();
+
+    // A freshly-created root has neither `/Nums` nor `/Kids` yet; `insert` must create the
+    // `/Nums` array rather than panicking on a missing one.
+    let root = qpdf.new_dictionary();
+    let tree = QpdfNumberTree::new(root.inner);
+
+    tree.insert(7, &qpdf.new_string("seven"));
+    assert_eq!(tree.find(7).unwrap().as_string(), "seven");
+    assert_eq!(tree.iter().map(|(k, _)| k).collect::<Vec<_>>(), vec![7]);
+}
+
+#[test]
+fn test_overlay_page() {
+    let qpdf = load_pdf();
+    let pages = qpdf.get_pages().unwrap();
+    let (dest, source) = (pages[0].clone(), pages[1].clone());
+
+    let dict: QpdfDictionary = dest.clone().into();
+    let resources_before: QpdfDictionary = dict.get("/Resources").unwrap().into();
+    let xobjects_before = resources_before.get("/XObject").map(|x| {
+        let x: QpdfDictionary = x.into();
+        x.keys().len()
+    });
+
+    qpdf.overlay_page(&dest, &source, false).unwrap();
+
+    let resources_after: QpdfDictionary = dict.get("/Resources").unwrap().into();
+    let xobject_dict: QpdfDictionary = resources_after.get("/XObject").unwrap().into();
+    assert!(xobject_dict.keys().len() > xobjects_before.unwrap_or(0));
+
+    let mem = qpdf.writer().write_to_memory().unwrap();
+    assert!(!mem.is_empty());
+}
+
+#[test]
+fn test_acroform() {
+    let qpdf = Qpdf::empty();
+
+    let field = qpdf
+        .parse_object(r#"<< /FT /Tx /T (name) /Rect [0 0 100 20] >>"#)
+        .unwrap()
+        .make_indirect();
+    let mut fields = qpdf.new_array();
+    fields.push(&field);
+
+    let acroform_dict = qpdf.new_dictionary_from([("/Fields", fields.inner().clone())]);
+    let root: QpdfDictionary = qpdf.get_root().unwrap().unwrap().into();
+    root.set("/AcroForm", acroform_dict.inner());
+
+    let acroform = qpdf.acroform().unwrap().unwrap();
+    let fields = acroform.fields();
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0].get_name().as_deref(), Some("name"));
+    assert_eq!(fields[0].get_field_type().as_deref(), Some("/Tx"));
+    assert!(fields[0].get_value().is_none());
+
+    fields[0].set_value(&qpdf.new_string("hello"), false);
+    assert_eq!(fields[0].get_value().unwrap().as_string(), "hello");
+
+    let field_dict: QpdfDictionary = field.clone().into();
+    let appearance: QpdfDictionary = field_dict.get("/AP").unwrap().into();
+    let stream = appearance.get("/N").unwrap();
+    let stream_dict: QpdfDictionary = stream.get_stream_dictionary().into();
+    let resources: QpdfDictionary = stream_dict.get("/Resources").unwrap().into();
+    let font: QpdfDictionary = resources.get("/Font").unwrap().into();
+    assert!(font.has("/Helv"));
+}
+
+#[test]
+fn test_acroform_checkbox_appearance_is_preserved() {
+    let qpdf = Qpdf::empty();
+
+    let checkbox = qpdf
+        .parse_object(
+            r#"<<
+                /FT /Btn
+                /T (agree)
+                /Rect [0 0 20 20]
+                /AS /Off
+                /AP << /N << /Yes 1 0 R /Off 2 0 R >> >>
+            >>"#,
+        )
+        .unwrap()
+        .make_indirect();
+    let mut fields = qpdf.new_array();
+    fields.push(&checkbox);
+
+    let acroform_dict = qpdf.new_dictionary_from([("/Fields", fields.inner().clone())]);
+    let root: QpdfDictionary = qpdf.get_root().unwrap().unwrap().into();
+    root.set("/AcroForm", acroform_dict.inner());
+
+    let acroform = qpdf.acroform().unwrap().unwrap();
+    let field = &acroform.fields()[0];
+    field.set_value(&qpdf.new_name("/Yes"), false);
+
+    // A checkbox's `/AP /N` is a dictionary of per-state streams selected by `/AS`; it must
+    // not be stomped with a single regenerated text-appearance stream.
+    let checkbox_dict: QpdfDictionary = checkbox.into();
+    let ap: QpdfDictionary = checkbox_dict.get("/AP").unwrap().into();
+    let n: QpdfDictionary = ap.get("/N").unwrap().into();
+    assert!(n.has("/Yes") && n.has("/Off"));
+
+    let root: QpdfDictionary = qpdf.get_root().unwrap().unwrap().into();
+    let acroform_dict: QpdfDictionary = root.get("/AcroForm").unwrap().into();
+    assert!(acroform_dict.get("/NeedAppearances").unwrap().as_bool());
+
+    // Renderers that don't regenerate appearances from `/NeedAppearances` still need `/AS` to
+    // point at the state that was just set, or they'll keep showing the old checkbox state.
+    assert_eq!(checkbox_dict.get("/AS").unwrap().as_name(), "/Yes");
+}
+
+#[test]
+fn test_acroform_value_is_inherited_from_parent() {
+    let qpdf = Qpdf::empty();
+
+    let parent = qpdf
+        .parse_object(r#"<< /FT /Tx /T (parent) /V (inherited) >>"#)
+        .unwrap()
+        .make_indirect();
+
+    let kid = qpdf.new_dictionary_from([
+        ("/T", qpdf.new_string("kid")),
+        ("/Rect", qpdf.parse_object("[0 0 100 20]").unwrap()),
+        ("/Parent", parent.clone()),
+    ]);
+
+    let mut fields = qpdf.new_array();
+    fields.push(kid.inner());
+
+    let acroform_dict = qpdf.new_dictionary_from([("/Fields", fields.inner().clone())]);
+    let root: QpdfDictionary = qpdf.get_root().unwrap().unwrap().into();
+    root.set("/AcroForm", acroform_dict.inner());
+
+    let acroform = qpdf.acroform().unwrap().unwrap();
+    let fields = acroform.fields();
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0].get_name().as_deref(), Some("parent.kid"));
+    assert_eq!(fields[0].get_value().unwrap().as_string(), "inherited");
+}
+
+#[test]
+fn test_attachments() {
+    let qpdf = Qpdf::empty();
+    assert!(qpdf.attachments().unwrap().is_empty());
+
+    qpdf.add_attachment("report.csv", "report.csv", b"a,b,c\n1,2,3\n", "text/csv", "Quarterly report")
+        .unwrap();
+
+    let attachments = qpdf.attachments().unwrap();
+    assert_eq!(attachments.len(), 1);
+    assert_eq!(attachments[0].get_name().as_deref(), Some("report.csv"));
+    assert_eq!(attachments[0].get_description().as_deref(), Some("Quarterly report"));
+    assert!(attachments[0].get_creation_date().is_some());
+    assert_eq!(attachments[0].get_data().unwrap().as_ref(), b"a,b,c\n1,2,3\n");
+}
+
 #[test]
 fn test_pdf_encrypted() {
     let qpdf = Qpdf::read("tests/data/encrypted.pdf");